@@ -0,0 +1,67 @@
+//! Layered resolution of `languages.toml`: builtin defaults, overridden by
+//! a user config, overridden by a workspace config.
+//!
+//! This mirrors the local language configuration layering added to the
+//! helix-loader lineage: each layer is merged onto the previous one with
+//! [`merge_toml_values`], so a workspace can add a grammar or tweak a
+//! `comment-token` without the user needing to touch their own config,
+//! and without balpan needing to be recompiled.
+
+use std::fs;
+
+use toml::Value;
+
+use crate::{lang_config_file, merge_toml_values, workspace_config_file};
+
+/// Depth passed to [`merge_toml_values`] for `languages.toml`: the
+/// top-level document table, the `[[language]]`/`[[grammars]]` arrays
+/// (entries matched by `name`), and each entry's table. Tables nested
+/// within an entry (e.g. `indent`, `language-server`) are replaced as a
+/// whole rather than merged.
+const LANG_CONFIG_MERGE_DEPTH: usize = 3;
+
+/// The built-in `languages.toml`, unmerged.
+pub fn default_lang_config() -> Value {
+    let raw = include_str!("../languages.toml");
+    toml::from_str(raw).expect("Couldn't parse built-in languages config")
+}
+
+/// The built-in config merged with the user's `languages.toml`
+/// (`config_dir()/languages.toml`), if one exists.
+pub fn user_lang_config() -> Value {
+    let default = default_lang_config();
+
+    let Ok(raw) = fs::read_to_string(lang_config_file()) else {
+        return default;
+    };
+    let user: Value = match toml::from_str(&raw) {
+        Ok(user) => user,
+        Err(err) => {
+            log::error!("Failed to parse user languages.toml, ignoring it: {err}");
+            return default;
+        }
+    };
+
+    merge_toml_values(default, user, LANG_CONFIG_MERGE_DEPTH)
+}
+
+/// The effective language config: builtin, layered with the user config,
+/// layered with the workspace's `.balpan/config.toml`, if one exists.
+///
+/// This is the config the analyzer should consume.
+pub fn workspace_lang_config() -> Value {
+    let layered = user_lang_config();
+
+    let Ok(raw) = fs::read_to_string(workspace_config_file()) else {
+        return layered;
+    };
+    let workspace: Value = match toml::from_str(&raw) {
+        Ok(workspace) => workspace,
+        Err(err) => {
+            log::error!("Failed to parse workspace .balpan/config.toml, ignoring it: {err}");
+            return layered;
+        }
+    };
+
+    merge_toml_values(layered, workspace, LANG_CONFIG_MERGE_DEPTH)
+}
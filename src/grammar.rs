@@ -0,0 +1,225 @@
+//! Fetching and building tree-sitter grammars that aren't bundled into
+//! the balpan binary, declared as `[[grammars]]` entries in
+//! `languages.toml` (`name`, a git `source` URL, and a pinned `rev`).
+//!
+//! This is the same fetch/build split that was moved into helix-loader,
+//! adapted to balpan's [`crate::runtime_dirs`] priority search: sources
+//! are cloned/checked out under `runtime/grammars/<name>/src`, compiled
+//! with the `cc` crate into a shared object alongside them, and loaded at
+//! runtime with `libloading`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{anyhow, Context, Result};
+use libloading::{Library, Symbol};
+use rayon::prelude::*;
+use toml::Value;
+
+use crate::{config, find_runtime_file, runtime_dirs};
+
+/// A `[[grammars]]` entry from `languages.toml`.
+#[derive(Debug, Clone)]
+pub struct GrammarSource {
+    pub name: String,
+    pub source: String,
+    pub rev: String,
+}
+
+/// The `[[grammars]]` entries from the effective (builtin + user +
+/// workspace) language config.
+pub fn grammar_sources() -> Vec<GrammarSource> {
+    let lang_config = config::workspace_lang_config();
+    let Some(grammars) = lang_config.get("grammars").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    grammars
+        .iter()
+        .filter_map(|grammar| {
+            Some(GrammarSource {
+                name: grammar.get("name")?.as_str()?.to_owned(),
+                source: grammar.get("source")?.as_str()?.to_owned(),
+                rev: grammar.get("rev")?.as_str()?.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Where `name`'s grammar checkout lives, relative to a runtime dir.
+fn grammar_source_rel_dir(name: &str) -> PathBuf {
+    Path::new("grammars").join(name).join("src")
+}
+
+/// Where `name`'s compiled shared object lives, relative to a runtime dir.
+fn grammar_artifact_rel_path(name: &str) -> PathBuf {
+    let file_name = format!(
+        "{}{}{}",
+        std::env::consts::DLL_PREFIX,
+        name,
+        std::env::consts::DLL_SUFFIX
+    );
+    Path::new("grammars").join(name).join(file_name)
+}
+
+/// Clones (or, if already cloned, fetches and checks out) every
+/// `[[grammars]]` source into `runtime/grammars/<name>/src` under the
+/// highest-priority runtime dir, in parallel.
+pub fn fetch_grammars() -> Result<()> {
+    grammar_sources()
+        .into_par_iter()
+        .try_for_each(fetch_grammar)
+}
+
+fn fetch_grammar(grammar: GrammarSource) -> Result<()> {
+    let dir = runtime_dirs()[0].join(grammar_source_rel_dir(&grammar.name));
+
+    if dir.join(".git").exists() {
+        run_git(&dir, &["fetch", "--depth", "1", "origin", &grammar.rev])?;
+    } else {
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("creating grammar source dir for {}", grammar.name))?;
+        run_git(&dir, &["clone", "--depth", "1", &grammar.source, "."])?;
+        run_git(&dir, &["fetch", "--depth", "1", "origin", &grammar.rev])?;
+    }
+
+    run_git(&dir, &["checkout", &grammar.rev])
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> Result<()> {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .with_context(|| format!("running `git {}` in {}", args.join(" "), dir.display()))?;
+
+    if !status.success() {
+        return Err(anyhow!("`git {}` failed in {}", args.join(" "), dir.display()));
+    }
+
+    Ok(())
+}
+
+/// Compiles every `[[grammars]]` source that was fetched via
+/// [`fetch_grammars`] into a shared object, in parallel. A grammar whose
+/// shared object is newer than its `parser.c`/scanner sources is skipped.
+pub fn build_grammars() -> Result<()> {
+    grammar_sources()
+        .into_par_iter()
+        .try_for_each(build_grammar)
+}
+
+fn build_grammar(grammar: GrammarSource) -> Result<()> {
+    // tree-sitter grammar repositories keep `parser.c` and the scanner
+    // under their own `src/` directory.
+    let src_dir = runtime_dirs()[0]
+        .join(grammar_source_rel_dir(&grammar.name))
+        .join("src");
+    let artifact_rel_path = grammar_artifact_rel_path(&grammar.name);
+
+    let mut sources = vec![src_dir.join("parser.c")];
+    for scanner in ["scanner.c", "scanner.cc"] {
+        let path = src_dir.join(scanner);
+        if path.exists() {
+            sources.push(path);
+        }
+    }
+
+    // An already-built artifact might live in a lower-priority runtime
+    // dir (e.g. one shipped alongside the binary); the first one found in
+    // priority order is checked, and the grammar is rebuilt unless that
+    // artifact is at least as new as the sources we just fetched.
+    if let Some(existing) = find_runtime_file(&artifact_rel_path) {
+        if is_up_to_date(&existing, &sources) {
+            return Ok(());
+        }
+    }
+
+    let artifact = runtime_dirs()[0].join(&artifact_rel_path);
+    std::fs::create_dir_all(artifact.parent().unwrap())
+        .with_context(|| format!("creating runtime dir for {}", grammar.name))?;
+
+    // `cc::Build` only knows how to produce static libs/object files, so
+    // it's used purely to resolve the right compiler (and its environment)
+    // for this target, and the shared object is linked by invoking that
+    // compiler directly with the flags given below.
+    let cpp = sources.iter().any(|path| path.extension().and_then(|e| e.to_str()) == Some("cc"));
+    let mut build = cc::Build::new();
+    build.cpp(cpp).include(&src_dir).pic(true);
+    let compiler = build.get_compiler();
+
+    let mut command = std::process::Command::new(compiler.path());
+    for (key, value) in compiler.env() {
+        command.env(key, value);
+    }
+    command.current_dir(&src_dir);
+    if cfg!(windows) {
+        command.args(["/LD", "/I"]).arg(&src_dir).arg("/Fe").arg(&artifact);
+    } else {
+        command
+            .arg("-shared")
+            .arg("-fPIC")
+            .arg("-I")
+            .arg(&src_dir)
+            .arg("-o")
+            .arg(&artifact);
+    }
+    command.args(&sources);
+
+    let output = command
+        .output()
+        .with_context(|| format!("invoking compiler for grammar {}", grammar.name))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "failed to compile grammar {}: {}",
+            grammar.name,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
+/// Whether `artifact` exists and is newer than every file in `sources`.
+fn is_up_to_date(artifact: &Path, sources: &[PathBuf]) -> bool {
+    let Ok(artifact_mtime) = artifact.metadata().and_then(|m| m.modified()) else {
+        return false;
+    };
+
+    sources.iter().all(|source| {
+        source
+            .metadata()
+            .and_then(|m| m.modified())
+            .map(|mtime| mtime <= artifact_mtime)
+            .unwrap_or(false)
+    })
+}
+
+/// Loads the tree-sitter grammar for `name` from its built shared object,
+/// locating it via [`find_runtime_file`] across [`runtime_dirs`] in
+/// priority order.
+///
+/// # Safety
+///
+/// This loads and calls into a dynamic library built from the grammar's
+/// own `parser.c`; the caller is trusted to have pointed `languages.toml`
+/// at a grammar that actually exports a `tree_sitter_<name>` symbol
+/// returning a valid `TSLanguage`.
+pub unsafe fn load_grammar(name: &str) -> Result<tree_sitter::Language> {
+    let artifact = find_runtime_file(&grammar_artifact_rel_path(name))
+        .ok_or_else(|| anyhow!("no built grammar found for {name}, run `balpan --fetch-grammars --build-grammars` first"))?;
+
+    let library = Library::new(&artifact)
+        .with_context(|| format!("loading grammar library for {name}"))?;
+    let symbol_name = format!("tree_sitter_{}", name.replace('-', "_"));
+    let language_fn: Symbol<unsafe extern "C" fn() -> tree_sitter::Language> = library
+        .get(symbol_name.as_bytes())
+        .with_context(|| format!("missing {symbol_name} symbol in grammar for {name}"))?;
+    let language = language_fn();
+
+    // The library must outlive any use of `language`; leak it so the
+    // loaded symbol stays valid for the lifetime of the process.
+    std::mem::forget(library);
+
+    Ok(language)
+}
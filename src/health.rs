@@ -0,0 +1,171 @@
+//! Diagnostic report for `balpan --health`, mirroring helix's `health.rs`.
+//!
+//! Prints which runtime directories actually exist (and in what priority
+//! order), where each config file is expected to live, and, for every
+//! language in the merged config, whether its grammar is available and
+//! whether it can actually be loaded — so a "language not analyzed"
+//! failure doesn't happen silently.
+
+use std::path::{Path, PathBuf};
+
+use toml::Value;
+
+use crate::{config, config_file, find_runtime_file, grammar, lang_config_file, log_file, runtime_dirs, workspace_config_file};
+
+/// Whether a checked path exists on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStatus {
+    Found,
+    Missing,
+}
+
+/// A single runtime directory and whether it exists, in priority order.
+#[derive(Debug)]
+pub struct RuntimeDirStatus {
+    pub path: PathBuf,
+    pub status: PathStatus,
+}
+
+/// Health of a single language's grammar: whether a grammar is available
+/// (bundled into the binary, or a built artifact was found), and whether
+/// it actually loads.
+#[derive(Debug)]
+pub struct LanguageHealth {
+    pub name: String,
+    pub grammar_found: bool,
+    pub loads: Result<(), String>,
+}
+
+/// The full diagnostic report produced by [`run`].
+#[derive(Debug)]
+pub struct HealthReport {
+    pub runtime_dirs: Vec<RuntimeDirStatus>,
+    pub config_file: (PathBuf, PathStatus),
+    pub lang_config_file: (PathBuf, PathStatus),
+    pub workspace_config_file: (PathBuf, PathStatus),
+    pub log_file: (PathBuf, PathStatus),
+    pub languages: Vec<LanguageHealth>,
+}
+
+fn status_of(path: &Path) -> PathStatus {
+    if path.exists() {
+        PathStatus::Found
+    } else {
+        PathStatus::Missing
+    }
+}
+
+/// Builds the diagnostic report without printing anything, so it can be
+/// tested or rendered differently by a caller.
+pub fn run() -> HealthReport {
+    let runtime_dirs = runtime_dirs()
+        .iter()
+        .map(|path| RuntimeDirStatus {
+            path: path.clone(),
+            status: status_of(path),
+        })
+        .collect();
+
+    let config_file = config_file();
+    let lang_config_file = lang_config_file();
+    let workspace_config_file = workspace_config_file();
+    let log_file = log_file();
+
+    let lang_config = config::workspace_lang_config();
+    let languages = lang_config
+        .get("language")
+        .and_then(Value::as_array)
+        .map(|langs| langs.iter().filter_map(language_health).collect())
+        .unwrap_or_default();
+
+    HealthReport {
+        runtime_dirs,
+        config_file: (config_file.clone(), status_of(&config_file)),
+        lang_config_file: (lang_config_file.clone(), status_of(&lang_config_file)),
+        workspace_config_file: (workspace_config_file.clone(), status_of(&workspace_config_file)),
+        log_file: (log_file.clone(), status_of(&log_file)),
+        languages,
+    }
+}
+
+fn language_health(lang: &Value) -> Option<LanguageHealth> {
+    let name = lang.get("name")?.as_str()?.to_owned();
+
+    let artifact_rel_path = Path::new("grammars").join(&name).join(format!(
+        "{}{}{}",
+        std::env::consts::DLL_PREFIX,
+        name,
+        std::env::consts::DLL_SUFFIX
+    ));
+    let grammar_found = find_runtime_file(&artifact_rel_path).is_some() || crate::language::tree_sitter_language(&name).is_some();
+
+    let loads = if crate::language::tree_sitter_language(&name).is_some() {
+        Ok(())
+    } else {
+        // SAFETY: only used to check whether the symbol loads; the
+        // resulting `Language` is dropped immediately.
+        match unsafe { grammar::load_grammar(&name) } {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err.to_string()),
+        }
+    };
+
+    Some(LanguageHealth {
+        name,
+        grammar_found,
+        loads,
+    })
+}
+
+/// Prints [`run`]'s report to stdout, with actionable messages for
+/// missing grammars and missing config files. Config files are only
+/// checked for existence: one that exists but cannot be read or parsed
+/// is reported as found.
+pub fn print_report() {
+    let report = run();
+
+    println!("Runtime directories (highest priority first):");
+    for dir in &report.runtime_dirs {
+        println!("  [{}] {}", status_glyph(dir.status), dir.path.display());
+    }
+
+    println!();
+    println!("Config files:");
+    print_path_status("config file", &report.config_file);
+    print_path_status("language config", &report.lang_config_file);
+    print_path_status("workspace config", &report.workspace_config_file);
+    print_path_status("log file", &report.log_file);
+
+    println!();
+    println!("Languages:");
+    for lang in &report.languages {
+        match &lang.loads {
+            Ok(()) => println!("  [✓] {} loads correctly", lang.name),
+            Err(err) if lang.grammar_found => println!(
+                "  [x] {}: grammar artifact found but failed to load ({err})",
+                lang.name
+            ),
+            Err(err) => println!(
+                "  [x] {}: no grammar artifact found — run `balpan --fetch-grammars --build-grammars` ({err})",
+                lang.name
+            ),
+        }
+    }
+}
+
+fn print_path_status(label: &str, (path, status): &(PathBuf, PathStatus)) {
+    match status {
+        PathStatus::Found => println!("  [✓] {label}: {}", path.display()),
+        PathStatus::Missing => println!(
+            "  [x] {label}: {} does not exist yet (using built-in defaults)",
+            path.display()
+        ),
+    }
+}
+
+fn status_glyph(status: PathStatus) -> &'static str {
+    match status {
+        PathStatus::Found => "✓",
+        PathStatus::Missing => "x",
+    }
+}
@@ -0,0 +1,88 @@
+//! Language metadata: tree-sitter grammars and per-language settings
+//! (such as the comment token used when emitting `[TODO]` markers)
+//! resolved from `languages.toml`.
+
+use toml::Value;
+
+use crate::config;
+
+/// The comment token used for `[TODO]` markers when a language doesn't
+/// configure its own `comment-token` and has no block-comment fallback.
+const FALLBACK_COMMENT_TOKEN: &str = "#";
+
+/// Returns the `[[language]]` table for `name`, if one is configured.
+pub fn find_language<'a>(config: &'a Value, name: &str) -> Option<&'a Value> {
+    config
+        .get("language")?
+        .as_array()?
+        .iter()
+        .find(|lang| lang.get("name").and_then(Value::as_str) == Some(name))
+}
+
+/// Returns the tree-sitter grammar for `name`, or `None` if balpan has no
+/// grammar bundled for it. Grammars fetched and built at runtime are not
+/// consulted here.
+///
+/// Note: the `sql` grammar is published on crates.io as `tree-sitter-sequel`
+/// (the `tree-sitter-sql` crate never moved past a tree-sitter 0.19 binding).
+pub fn tree_sitter_language(name: &str) -> Option<tree_sitter::Language> {
+    match name {
+        "python" => Some(tree_sitter_python::LANGUAGE.into()),
+        "rust" => Some(tree_sitter_rust::LANGUAGE.into()),
+        "c" => Some(tree_sitter_c::LANGUAGE.into()),
+        "lua" => Some(tree_sitter_lua::language()),
+        "sql" => Some(tree_sitter_sequel::LANGUAGE.into()),
+        "css" => Some(tree_sitter_css::LANGUAGE.into()),
+        _ => None,
+    }
+}
+
+/// How a language spells out comments, resolved from its `languages.toml`
+/// entry.
+enum CommentStyle {
+    /// A line comment token, e.g. `#` or `//`.
+    Line(String),
+    /// A block comment's start/end delimiters, e.g. `/*` and `*/`.
+    Block(String, String),
+    /// Neither was configured.
+    None,
+}
+
+fn comment_style(config: &Value, name: &str) -> CommentStyle {
+    let Some(lang) = find_language(config, name) else {
+        return CommentStyle::None;
+    };
+
+    if let Some(token) = lang.get("comment-token").and_then(Value::as_str) {
+        return CommentStyle::Line(token.to_owned());
+    }
+
+    if let Some(block) = lang.get("block-comment-tokens") {
+        let start = block.get("start").and_then(Value::as_str);
+        let end = block.get("end").and_then(Value::as_str);
+        if let (Some(start), Some(end)) = (start, end) {
+            return CommentStyle::Block(start.to_owned(), end.to_owned());
+        }
+    }
+
+    CommentStyle::None
+}
+
+/// Builds the `[TODO]` marker comment for `name` using the effective
+/// language config (builtin, layered with user and workspace overrides
+/// via [`config::workspace_lang_config`]), preferring a line comment,
+/// falling back to a block comment, and finally to
+/// [`FALLBACK_COMMENT_TOKEN`].
+pub fn marker_comment(name: &str) -> String {
+    marker_comment_with(&config::workspace_lang_config(), name)
+}
+
+/// Same as [`marker_comment`], but resolved against an arbitrary (e.g.
+/// merged) language config rather than re-reading it from disk.
+pub fn marker_comment_with(config: &Value, name: &str) -> String {
+    match comment_style(config, name) {
+        CommentStyle::Line(token) => format!("{token} [TODO]"),
+        CommentStyle::Block(start, end) => format!("{start} [TODO] {end}"),
+        CommentStyle::None => format!("{FALLBACK_COMMENT_TOKEN} [TODO]"),
+    }
+}
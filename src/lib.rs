@@ -3,12 +3,13 @@
 pub mod config;
 pub mod grammar;
 pub mod analyzer;
-pub mod utils;
 pub mod tree_sitter_extended;
 pub mod language;
+pub mod health;
 
 use etcetera::base_strategy::{choose_base_strategy, BaseStrategy};
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 use toml::{map::Map, Value};
 
 static RUNTIME_DIRS: once_cell::sync::Lazy<Vec<PathBuf>> =
@@ -16,6 +17,44 @@ static RUNTIME_DIRS: once_cell::sync::Lazy<Vec<PathBuf>> =
 
 static CONFIG_FILE: once_cell::sync::OnceCell<PathBuf> = once_cell::sync::OnceCell::new();
 
+/// balpan's managed view of the current working directory. `None` until
+/// the first call to [`current_working_dir`], which populates it from
+/// the environment, or to [`set_current_working_dir`].
+static CURRENT_WORKING_DIR: once_cell::sync::Lazy<RwLock<Option<PathBuf>>> =
+    once_cell::sync::Lazy::new(|| RwLock::new(None));
+
+/// Returns balpan's managed current working directory, canonicalized
+/// with `dunce` so it stays free of Windows' `\\?\` UNC prefix.
+///
+/// The first call reads and caches `std::env::current_dir()`; later
+/// calls return the cached value instead of hitting the environment
+/// again, and never panic even if the process's cwd was since deleted
+/// (unlike a bare `std::env::current_dir().expect(..)`). Use
+/// [`set_current_working_dir`] to change it.
+pub fn current_working_dir() -> PathBuf {
+    if let Some(path) = CURRENT_WORKING_DIR.read().unwrap().as_ref() {
+        return path.clone();
+    }
+
+    let cwd = std::env::current_dir()
+        .ok()
+        .and_then(|path| dunce::canonicalize(path).ok())
+        .unwrap_or_else(|| PathBuf::from("."));
+    *CURRENT_WORKING_DIR.write().unwrap() = Some(cwd.clone());
+    cwd
+}
+
+/// Overrides balpan's managed current working directory, canonicalizing
+/// `path` with `dunce` first. Subsequent calls to [`current_working_dir`]
+/// return this value instead of re-reading the environment, which keeps
+/// ancestor-walking (e.g. in [`find_workspace`]) deterministic for
+/// long-running/LSP-style usage.
+pub fn set_current_working_dir(path: impl AsRef<Path>) -> std::io::Result<()> {
+    let canonicalized = dunce::canonicalize(path)?;
+    *CURRENT_WORKING_DIR.write().unwrap() = Some(canonicalized);
+    Ok(())
+}
+
 pub fn initialize_config_file(specified_file: Option<PathBuf>) {
     let config_file = specified_file.unwrap_or_else(|| {
         let config_dir = config_dir();
@@ -259,7 +298,7 @@ fn toml_table_value(
 /// If no workspace was found returns (CWD, true).
 /// Otherwise (workspace, false) is returned
 pub fn find_workspace() -> (PathBuf, bool) {
-    let current_dir = std::env::current_dir().expect("unable to determine current directory");
+    let current_dir = current_working_dir();
     for ancestor in current_dir.ancestors() {
         if ancestor.join(".git").exists() || ancestor.join(".balpan").exists() {
             return (ancestor.to_owned(), false);
@@ -0,0 +1,24 @@
+//! balpan's CLI entry point. The bulk of the logic lives in the `balpan`
+//! library crate; this binary just wires flags to it.
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--fetch-grammars") {
+        if let Err(err) = balpan::grammar::fetch_grammars() {
+            eprintln!("error: failed to fetch grammars: {err}");
+            std::process::exit(1);
+        }
+    }
+
+    if args.iter().any(|arg| arg == "--build-grammars") {
+        if let Err(err) = balpan::grammar::build_grammars() {
+            eprintln!("error: failed to build grammars: {err}");
+            std::process::exit(1);
+        }
+    }
+
+    if args.iter().any(|arg| arg == "--health") {
+        balpan::health::print_report();
+    }
+}
@@ -0,0 +1,40 @@
+//! Small extensions over `tree_sitter::Node` that the analyzer relies on.
+//!
+//! `tree-sitter`'s own `Node` API is deliberately low-level; these helpers
+//! cover the handful of queries the analyzer needs repeatedly (walking
+//! children, checking whether a node is wrapped by a decorator, ...)
+//! without pulling in the full tree-sitter query engine.
+
+use tree_sitter::Node;
+
+/// Convenience methods layered on top of [`tree_sitter::Node`].
+pub trait NodeExt<'tree> {
+    /// Returns all direct children of this node (named and anonymous) as
+    /// a `Vec`.
+    ///
+    /// `tree_sitter::Node::children` requires a `TreeCursor` to be kept
+    /// alive by the caller, which is awkward for simple recursive walks;
+    /// this collects into an owned `Vec` instead.
+    fn children_vec(&self) -> Vec<Node<'tree>>;
+
+    /// Whether this node's immediate parent is of the given `kind`.
+    fn parent_is(&self, kind: &str) -> bool;
+
+    /// The 0-indexed source line this node starts on.
+    fn start_line(&self) -> usize;
+}
+
+impl<'tree> NodeExt<'tree> for Node<'tree> {
+    fn children_vec(&self) -> Vec<Node<'tree>> {
+        let mut cursor = self.walk();
+        self.children(&mut cursor).collect()
+    }
+
+    fn parent_is(&self, kind: &str) -> bool {
+        self.parent().map(|p| p.kind() == kind).unwrap_or(false)
+    }
+
+    fn start_line(&self) -> usize {
+        self.start_position().row
+    }
+}
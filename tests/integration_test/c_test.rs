@@ -0,0 +1,18 @@
+use indoc::indoc;
+use crate::integration_test::assert_analyzed_source_code;
+
+#[test]
+fn test_function_definition() {
+    let source_code = indoc! {"
+    int add(int a, int b) {
+        return a + b;
+    }"};
+
+    let result = indoc! {"
+    // [TODO]
+    int add(int a, int b) {
+        return a + b;
+    }"};
+
+    assert_analyzed_source_code(source_code, result, "c")
+}
@@ -0,0 +1,20 @@
+use indoc::indoc;
+use crate::integration_test::assert_analyzed_source_code;
+
+// css has no line comment, so this also exercises the block-comment
+// fallback in `language::marker_comment`.
+#[test]
+fn test_rule_set() {
+    let source_code = indoc! {"
+    .card {
+        padding: 1rem;
+    }"};
+
+    let result = indoc! {"
+    /* [TODO] */
+    .card {
+        padding: 1rem;
+    }"};
+
+    assert_analyzed_source_code(source_code, result, "css")
+}
@@ -0,0 +1,18 @@
+use indoc::indoc;
+use crate::integration_test::assert_analyzed_source_code;
+
+#[test]
+fn test_function_definition() {
+    let source_code = indoc! {"
+    function greet(name)
+        print(\"hello \" .. name)
+    end"};
+
+    let result = indoc! {"
+    -- [TODO]
+    function greet(name)
+        print(\"hello \" .. name)
+    end"};
+
+    assert_analyzed_source_code(source_code, result, "lua")
+}
@@ -0,0 +1,15 @@
+mod python_test;
+mod rust_test;
+mod c_test;
+mod lua_test;
+mod sql_test;
+mod css_test;
+
+pub mod integration_test {
+    use balpan::analyzer::analyze_source_code;
+
+    pub fn assert_analyzed_source_code(source_code: &str, expected: &str, language: &str) {
+        let actual = analyze_source_code(source_code, language);
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1 @@
+mod django_case_test;
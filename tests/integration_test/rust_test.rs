@@ -0,0 +1,29 @@
+use indoc::indoc;
+use crate::integration_test::assert_analyzed_source_code;
+
+#[test]
+fn test_struct_and_function_definitions() {
+    let source_code = indoc! {"
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    fn distance(a: Point, b: Point) -> f64 {
+        0.0
+    }"};
+
+    let result = indoc! {"
+    // [TODO]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // [TODO]
+    fn distance(a: Point, b: Point) -> f64 {
+        0.0
+    }"};
+
+    assert_analyzed_source_code(source_code, result, "rust")
+}
@@ -0,0 +1,20 @@
+use indoc::indoc;
+use crate::integration_test::assert_analyzed_source_code;
+
+#[test]
+fn test_create_table_statement() {
+    let source_code = indoc! {"
+    CREATE TABLE users (
+        id INTEGER PRIMARY KEY,
+        name TEXT
+    );"};
+
+    let result = indoc! {"
+    -- [TODO]
+    CREATE TABLE users (
+        id INTEGER PRIMARY KEY,
+        name TEXT
+    );"};
+
+    assert_analyzed_source_code(source_code, result, "sql")
+}